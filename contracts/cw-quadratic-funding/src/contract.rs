@@ -255,6 +255,10 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::ProposalByID { id } => to_binary(&query_proposal_id(deps, id)?),
         QueryMsg::AllProposals {} => to_binary(&query_all_proposals(deps)?),
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -275,13 +279,14 @@ fn query_all_proposals(deps: Deps) -> StdResult<AllProposalsResponse> {
 
 #[cfg(test)]
 mod tests {
-    use crate::contract::{execute, init, query_all_proposals, query_proposal_id};
+    use crate::contract::{execute, init, query, query_all_proposals, query_proposal_id};
+    use crate::debug::RawStorageResponse;
     use crate::error::ContractError;
     use crate::matching::QuadraticFundingAlgorithm;
-    use crate::msg::{AllProposalsResponse, ExecuteMsg, InitMsg};
-    use crate::state::{Proposal, PROPOSALS};
+    use crate::msg::{AllProposalsResponse, ExecuteMsg, InitMsg, QueryMsg};
+    use crate::state::{Proposal, PROPOSALS, PROPOSAL_SEQ};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{coin, BankMsg, Binary, CosmosMsg, SubMsg};
+    use cosmwasm_std::{coin, from_binary, from_slice, BankMsg, Binary, CosmosMsg, SubMsg};
     use cw0::Expiration;
 
     #[test]
@@ -641,4 +646,43 @@ mod tests {
             res
         );
     }
+
+    #[test]
+    fn query_raw_storage() {
+        let mut deps = mock_dependencies(&[]);
+
+        let proposal = Proposal {
+            id: 1,
+            title: "title".to_string(),
+            description: "desc".to_string(),
+            metadata: None,
+            ..Default::default()
+        };
+        let _ = PROPOSALS.save(&mut deps.storage, 1_u64.into(), &proposal);
+        let _ = PROPOSAL_SEQ.save(&mut deps.storage, &1);
+
+        // the length prefixed proposal key sorts before the sequence
+        let msg = QueryMsg::RawStorage {
+            start_after: None,
+            limit: Some(1),
+        };
+        let page: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(1, page.entries.len());
+        let (key, value) = &page.entries[0];
+        let res: Proposal = from_slice(value).unwrap();
+        assert_eq!(proposal, res);
+
+        let msg = QueryMsg::RawStorage {
+            start_after: Some(key.clone()),
+            limit: None,
+        };
+        let page: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(1, page.entries.len());
+        let (key, value) = &page.entries[0];
+        assert_eq!(&b"proposal_seq"[..], key.as_slice());
+        let seq: u64 = from_slice(value).unwrap();
+        assert_eq!(1, seq);
+    }
 }
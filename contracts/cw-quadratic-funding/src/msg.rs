@@ -52,8 +52,17 @@ pub enum ExecuteMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    ProposalByID { id: u64 },
+    ProposalByID {
+        id: u64,
+    },
     AllProposals {},
+    /// Lists raw storage entries in key order, for tests and local debugging.
+    /// Only compiled in test builds or with the "debug" feature.
+    #[cfg(any(test, feature = "debug"))]
+    RawStorage {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -138,6 +138,10 @@ pub fn receive_send(
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetPot { id } => to_binary(&query_pot(deps, id)?),
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -153,8 +157,9 @@ fn query_pot(deps: Deps, id: Uint64) -> StdResult<PotResponse> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::debug::RawStorageResponse;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{from_binary, Addr, CosmosMsg, WasmMsg};
+    use cosmwasm_std::{from_binary, from_slice, Addr, CosmosMsg, WasmMsg};
 
     #[test]
     fn create_pot() {
@@ -267,4 +272,47 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn raw_storage() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            admin: None,
+            cw20_addr: String::from("cw20"),
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+
+        let msg = ExecuteMsg::CreatePot {
+            target_addr: String::from("some"),
+            threshold: Uint128::new(100),
+        };
+        let _res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // first page holds the pot (its key is length prefixed) and the config
+        let msg = QueryMsg::RawStorage {
+            start_after: None,
+            limit: Some(2),
+        };
+        let page: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let keys: Vec<&[u8]> = page.entries.iter().map(|(k, _)| k.as_slice()).collect();
+        let pot_key = POTS.key(1);
+        assert_eq!(keys, vec![&*pot_key, &b"config"[..]]);
+        let pot: Pot = from_slice(&page.entries[0].1).unwrap();
+        assert_eq!(pot.threshold, Uint128::new(100));
+
+        // second page continues with the cw2 version info and the sequence
+        let msg = QueryMsg::RawStorage {
+            start_after: Some(page.entries[1].0.clone()),
+            limit: Some(2),
+        };
+        let page: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let keys: Vec<&[u8]> = page.entries.iter().map(|(k, _)| k.as_slice()).collect();
+        assert_eq!(keys, vec![&b"contract_info"[..], &b"pot_seq"[..]]);
+        let seq: u64 = from_slice(&page.entries[1].1).unwrap();
+        assert_eq!(seq, 1);
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(any(test, feature = "debug"))]
+use cosmwasm_std::Binary;
 use cosmwasm_std::{Uint128, Uint64};
 use cw20::Cw20ReceiveMsg;
 use schemars::JsonSchema;
@@ -34,7 +36,16 @@ pub enum ReceiveMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     // GetPot returns pot with given id
-    GetPot { id: Uint64 },
+    GetPot {
+        id: Uint64,
+    },
+    /// Lists raw storage entries in key order, for tests and local debugging.
+    /// Only compiled in test builds or with the "debug" feature.
+    #[cfg(any(test, feature = "debug"))]
+    RawStorage {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -111,6 +111,10 @@ fn send_tokens(to_address: Addr, amount: Vec<Coin>, action: &str) -> Response {
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Arbiter {} => to_binary(&query_arbiter(deps)?),
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -123,8 +127,10 @@ fn query_arbiter(deps: Deps) -> StdResult<ArbiterResponse> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::debug::RawStorageResponse;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{coins, CosmosMsg, Timestamp};
+    use cosmwasm_std::{coins, from_binary, from_slice, CosmosMsg, Timestamp};
+    use cosmwasm_storage::to_length_prefixed;
 
     fn init_msg_expire_by_height(height: u64) -> InstantiateMsg {
         InstantiateMsg {
@@ -339,4 +345,39 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn raw_storage() {
+        let mut deps = mock_dependencies(&[]);
+
+        let msg = init_msg_expire_by_height(1000);
+        let mut env = mock_env();
+        env.block.height = 876;
+        env.block.time = Timestamp::from_seconds(0);
+        let info = mock_info("creator", &coins(1000, "earth"));
+        let _ = instantiate(deps.as_mut(), env, info, msg).unwrap();
+
+        let query_msg = QueryMsg::RawStorage {
+            start_after: None,
+            limit: None,
+        };
+        let res: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+        assert_eq!(1, res.entries.len());
+
+        let (key, value) = &res.entries[0];
+        assert_eq!(to_length_prefixed(b"config"), key.as_slice());
+        let state: State = from_slice(value).unwrap();
+        assert_eq!(Addr::unchecked("creator"), state.source);
+        assert_eq!(Some(1000), state.end_height);
+
+        // nothing after the last key
+        let query_msg = QueryMsg::RawStorage {
+            start_after: Some(key.clone()),
+            limit: None,
+        };
+        let res: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), query_msg).unwrap()).unwrap();
+        assert!(res.entries.is_empty());
+    }
 }
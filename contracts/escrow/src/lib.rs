@@ -1,4 +1,6 @@
 pub mod contract;
+#[cfg(any(test, feature = "debug"))]
+pub mod debug;
 mod error;
 pub mod msg;
 pub mod state;
@@ -1,3 +1,5 @@
+#[cfg(any(test, feature = "debug"))]
+use cosmwasm_std::Binary;
 use cosmwasm_std::{Addr, Coin};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -30,6 +32,13 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     /// Returns a human-readable representation of the arbiter.
     Arbiter {},
+    /// Lists raw storage entries in key order, for tests and local debugging.
+    /// Only compiled in test builds or with the "debug" feature.
+    #[cfg(any(test, feature = "debug"))]
+    RawStorage {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
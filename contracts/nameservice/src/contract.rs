@@ -97,6 +97,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::ResolveRecord { name } => query_resolver(deps, env, name),
         QueryMsg::Config {} => to_binary(&config_read(deps.storage).load()?),
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
     }
 }
 
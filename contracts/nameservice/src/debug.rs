@@ -0,0 +1,31 @@
+use cosmwasm_std::{Binary, Deps, Order, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RawStorageResponse {
+    /// Raw (key, value) pairs in ascending key order
+    pub entries: Vec<(Binary, Binary)>,
+}
+
+/// Dumps storage without knowing the key layout, so tests can assert on the
+/// complete contract state. Not part of the public interface.
+pub fn query_raw_storage(
+    deps: Deps,
+    start_after: Option<Binary>,
+    limit: Option<u32>,
+) -> StdResult<RawStorageResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // appending a zero byte gives the smallest key after start_after
+    let start = start_after.map(|key| [key.as_slice(), &[0u8]].concat());
+    let entries = deps
+        .storage
+        .range(start.as_deref(), None, Order::Ascending)
+        .take(limit)
+        .map(|(key, value)| (Binary(key), Binary(value)))
+        .collect();
+    Ok(RawStorageResponse { entries })
+}
@@ -1,12 +1,14 @@
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{coin, coins, from_binary, Coin, Deps, DepsMut};
+    use cosmwasm_std::{coin, coins, from_binary, from_slice, Coin, Deps, DepsMut};
+    use cosmwasm_storage::to_length_prefixed;
 
     use crate::contract::{execute, instantiate, query};
+    use crate::debug::RawStorageResponse;
     use crate::error::ContractError;
     use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ResolveRecordResponse};
-    use crate::state::Config;
+    use crate::state::{Config, NameRecord, CONFIG_KEY, NAME_RESOLVER_KEY};
 
     fn assert_name_owner(deps: Deps, name: &str, owner: &str) {
         let res = query(
@@ -369,4 +371,52 @@ mod tests {
         let value: ResolveRecordResponse = from_binary(&res).unwrap();
         assert_eq!(None, value.address);
     }
+
+    #[test]
+    fn raw_storage_pages_through_all_entries() {
+        let mut deps = mock_dependencies(&[]);
+        mock_init_no_price(deps.as_mut());
+        mock_alice_registers_name(deps.as_mut(), &[]);
+
+        // config comes first, as its length prefix is shorter
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RawStorage {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+        let page: RawStorageResponse = from_binary(&res).unwrap();
+        assert_eq!(1, page.entries.len());
+        let (key, value) = &page.entries[0];
+        assert_eq!(to_length_prefixed(CONFIG_KEY), key.as_slice());
+        let config: Config = from_slice(value).unwrap();
+        assert_eq!(
+            Config {
+                purchase_price: None,
+                transfer_price: None,
+            },
+            config
+        );
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RawStorage {
+                start_after: Some(key.clone()),
+                limit: None,
+            },
+        )
+        .unwrap();
+        let page: RawStorageResponse = from_binary(&res).unwrap();
+        assert_eq!(1, page.entries.len());
+        let (key, value) = &page.entries[0];
+        let mut expected_key = to_length_prefixed(NAME_RESOLVER_KEY);
+        expected_key.extend_from_slice(b"alice");
+        assert_eq!(expected_key, key.as_slice());
+        let record: NameRecord = from_slice(value).unwrap();
+        assert_eq!("alice_key", record.owner.as_str());
+    }
 }
@@ -145,6 +145,10 @@ pub fn execute_burn(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Respon
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -156,8 +160,10 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::debug::RawStorageResponse;
+    use crate::state::CONFIG_KEY;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{attr, coins, CosmosMsg};
+    use cosmwasm_std::{attr, coins, from_binary, from_slice, CosmosMsg};
 
     #[test]
     fn proper_initialization() {
@@ -348,4 +354,39 @@ mod tests {
         // check deleted
         let _ = query_config(deps.as_ref()).unwrap_err();
     }
+
+    #[test]
+    fn raw_storage() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            counter_offer: coins(40, "ETH"),
+            expires: 100_000,
+        };
+        let info = mock_info("creator", &coins(1, "BTC"));
+        let _ = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let msg = QueryMsg::RawStorage {
+            start_after: None,
+            limit: None,
+        };
+        let res: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(res.entries.len(), 1);
+
+        let (key, value) = &res.entries[0];
+        assert_eq!(key.as_slice(), CONFIG_KEY.as_bytes());
+        let state: State = from_slice(value).unwrap();
+        assert_eq!("creator", state.owner.as_str());
+        assert_eq!(coins(1, "BTC"), state.collateral);
+
+        // nothing after the last key
+        let msg = QueryMsg::RawStorage {
+            start_after: Some(key.clone()),
+            limit: None,
+        };
+        let res: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert!(res.entries.is_empty());
+    }
 }
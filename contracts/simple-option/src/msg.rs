@@ -1,4 +1,6 @@
 use crate::state::State;
+#[cfg(any(test, feature = "debug"))]
+use cosmwasm_std::Binary;
 use cosmwasm_std::Coin;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -26,6 +28,13 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Config {},
+    /// Lists raw storage entries in key order, for tests and local debugging.
+    /// Only compiled in test builds or with the "debug" feature.
+    #[cfg(any(test, feature = "debug"))]
+    RawStorage {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -0,0 +1,510 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdResult, SubMsg,
+    SubMsgResult, WasmMsg,
+};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, ListPlansResponse, MigrateStep, PlanResponse,
+    QueryMsg,
+};
+use crate::state::{Config, Plan, PlanStatus, Step, StepStatus, CONFIG, PLANS, PLAN_SEQ};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let admin = match msg.admin {
+        Some(admin) => deps.api.addr_validate(&admin)?,
+        None => info.sender,
+    };
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            admin: admin.clone(),
+        },
+    )?;
+
+    // init plan sequence
+    PLAN_SEQ.save(deps.storage, &0u64)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("admin", admin))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::ExecutePlan { steps } => execute_plan(deps, info, steps),
+        ExecuteMsg::ResumePlan { plan_id } => execute_resume_plan(deps, info, plan_id),
+        ExecuteMsg::UpdateAdmin { admin } => execute_update_admin(deps, info, admin),
+    }
+}
+
+fn assert_admin(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn execute_plan(
+    deps: DepsMut,
+    info: MessageInfo,
+    steps: Vec<MigrateStep>,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+    if steps.is_empty() {
+        return Err(ContractError::EmptyPlan {});
+    }
+
+    let steps = steps
+        .into_iter()
+        .map(|step| {
+            Ok(Step {
+                contract_addr: deps.api.addr_validate(&step.contract_addr)?,
+                new_code_id: step.new_code_id,
+                msg: step.msg,
+                status: StepStatus::Pending,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let id = PLAN_SEQ.update(deps.storage, |id| -> StdResult<_> { Ok(id + 1) })?;
+    let plan = Plan {
+        id,
+        status: PlanStatus::InProgress,
+        current_step: 0,
+        steps,
+    };
+    PLANS.save(deps.storage, id, &plan)?;
+
+    Ok(Response::new()
+        .add_submessage(migrate_current_step(&plan))
+        .add_attribute("action", "execute_plan")
+        .add_attribute("plan_id", id.to_string())
+        .add_attribute("steps", plan.steps.len().to_string()))
+}
+
+pub fn execute_resume_plan(
+    deps: DepsMut,
+    info: MessageInfo,
+    plan_id: u64,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let mut plan = PLANS.load(deps.storage, plan_id)?;
+    if plan.status != PlanStatus::Halted {
+        return Err(ContractError::PlanNotHalted { plan_id });
+    }
+    plan.status = PlanStatus::InProgress;
+    plan.steps[plan.current_step as usize].status = StepStatus::Pending;
+    PLANS.save(deps.storage, plan_id, &plan)?;
+
+    Ok(Response::new()
+        .add_submessage(migrate_current_step(&plan))
+        .add_attribute("action", "resume_plan")
+        .add_attribute("plan_id", plan_id.to_string())
+        .add_attribute("step", plan.current_step.to_string()))
+}
+
+pub fn execute_update_admin(
+    deps: DepsMut,
+    info: MessageInfo,
+    admin: String,
+) -> Result<Response, ContractError> {
+    assert_admin(deps.as_ref(), &info)?;
+
+    let admin = deps.api.addr_validate(&admin)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            admin: admin.clone(),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_admin")
+        .add_attribute("admin", admin))
+}
+
+// A plan runs entirely within one transaction, each reply dispatching the next
+// step, so the plan ID is enough to identify the reply.
+fn migrate_current_step(plan: &Plan) -> SubMsg {
+    let step = &plan.steps[plan.current_step as usize];
+    SubMsg::reply_always(
+        WasmMsg::Migrate {
+            contract_addr: step.contract_addr.to_string(),
+            new_code_id: step.new_code_id,
+            msg: step.msg.clone(),
+        },
+        plan.id,
+    )
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let mut plan = PLANS.load(deps.storage, msg.id)?;
+    if plan.status != PlanStatus::InProgress {
+        return Err(ContractError::PlanNotInProgress { plan_id: plan.id });
+    }
+
+    let index = plan.current_step as usize;
+    let mut res = Response::new()
+        .add_attribute("action", "migrate_step")
+        .add_attribute("plan_id", plan.id.to_string())
+        .add_attribute("step", index.to_string())
+        .add_attribute("contract", plan.steps[index].contract_addr.as_str());
+
+    match msg.result {
+        SubMsgResult::Ok(_) => {
+            plan.steps[index].status = StepStatus::Succeeded;
+            res = res.add_attribute("result", "success");
+            if index + 1 < plan.steps.len() {
+                plan.current_step += 1;
+                res = res.add_submessage(migrate_current_step(&plan));
+            } else {
+                plan.status = PlanStatus::Completed;
+                res = res.add_attribute("plan_status", "completed");
+            }
+        }
+        SubMsgResult::Err(error) => {
+            // the failed migration was already reverted, later steps are never sent
+            plan.steps[index].status = StepStatus::Failed {
+                error: error.clone(),
+            };
+            plan.status = PlanStatus::Halted;
+            res = res
+                .add_attribute("result", "failure")
+                .add_attribute("error", error)
+                .add_attribute("plan_status", "halted");
+        }
+    }
+    PLANS.save(deps.storage, plan.id, &plan)?;
+
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::Plan { plan_id } => to_binary(&query_plan(deps, plan_id)?),
+        QueryMsg::ListPlans { start_after, limit } => {
+            to_binary(&query_list_plans(deps, start_after, limit)?)
+        }
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
+    }
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    CONFIG.load(deps.storage)
+}
+
+fn query_plan(deps: Deps, plan_id: u64) -> StdResult<PlanResponse> {
+    PLANS.load(deps.storage, plan_id)
+}
+
+fn query_list_plans(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ListPlansResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    let plans = PLANS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, plan)| plan))
+        .collect::<StdResult<_>>()?;
+    Ok(ListPlansResponse { plans })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::RawStorageResponse;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Addr, CosmosMsg, ReplyOn, SubMsgResponse};
+
+    const ADMIN: &str = "gov";
+
+    fn step(contract_addr: &str, new_code_id: u64) -> MigrateStep {
+        MigrateStep {
+            contract_addr: contract_addr.to_string(),
+            new_code_id,
+            msg: to_binary(&"migrate").unwrap(),
+        }
+    }
+
+    fn migrate_msg(contract_addr: &str, new_code_id: u64) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: contract_addr.to_string(),
+            new_code_id,
+            msg: to_binary(&"migrate").unwrap(),
+        })
+    }
+
+    fn success(id: u64) -> Reply {
+        Reply {
+            id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        }
+    }
+
+    fn failure(id: u64, error: &str) -> Reply {
+        Reply {
+            id,
+            result: SubMsgResult::Err(error.to_string()),
+        }
+    }
+
+    fn setup(deps: DepsMut) {
+        let msg = InstantiateMsg {
+            admin: Some(ADMIN.to_string()),
+        };
+        let info = mock_info("creator", &[]);
+        let _res = instantiate(deps, mock_env(), info, msg).unwrap();
+    }
+
+    fn load_plan(deps: Deps, plan_id: u64) -> Plan {
+        let res = query(deps, mock_env(), QueryMsg::Plan { plan_id }).unwrap();
+        from_binary(&res).unwrap()
+    }
+
+    #[test]
+    fn proper_initialization() {
+        let mut deps = mock_dependencies();
+
+        let info = mock_info("creator", &[]);
+        let msg = InstantiateMsg { admin: None };
+        let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        let res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+        let config: ConfigResponse = from_binary(&res).unwrap();
+        assert_eq!(config.admin, Addr::unchecked("creator"));
+    }
+
+    #[test]
+    fn only_admin_can_run_plans() {
+        let mut deps = mock_dependencies();
+        setup(deps.as_mut());
+
+        let msg = ExecuteMsg::ExecutePlan {
+            steps: vec![step("pot", 2)],
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("anyone", &[]), msg).unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let msg = ExecuteMsg::ExecutePlan { steps: vec![] };
+        let err = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap_err();
+        match err {
+            ContractError::EmptyPlan {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn steps_run_in_order() {
+        let mut deps = mock_dependencies();
+        setup(deps.as_mut());
+
+        let msg = ExecuteMsg::ExecutePlan {
+            steps: vec![step("pot", 2), step("escrow", 3)],
+        };
+        let res = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+        // only the first step is dispatched
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, migrate_msg("pot", 2));
+        assert_eq!(res.messages[0].id, 1);
+        assert_eq!(res.messages[0].reply_on, ReplyOn::Always);
+
+        // first migration succeeded, second one is dispatched
+        let res = reply(deps.as_mut(), mock_env(), success(1)).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, migrate_msg("escrow", 3));
+        let plan = load_plan(deps.as_ref(), 1);
+        assert_eq!(plan.status, PlanStatus::InProgress);
+        assert_eq!(plan.current_step, 1);
+        assert_eq!(plan.steps[0].status, StepStatus::Succeeded);
+        assert_eq!(plan.steps[1].status, StepStatus::Pending);
+
+        // last migration completes the plan
+        let res = reply(deps.as_mut(), mock_env(), success(1)).unwrap();
+        assert_eq!(res.messages.len(), 0);
+        let plan = load_plan(deps.as_ref(), 1);
+        assert_eq!(plan.status, PlanStatus::Completed);
+        assert_eq!(plan.steps[1].status, StepStatus::Succeeded);
+
+        // a stray reply for a finished plan is rejected
+        let err = reply(deps.as_mut(), mock_env(), success(1)).unwrap_err();
+        match err {
+            ContractError::PlanNotInProgress { plan_id } => assert_eq!(plan_id, 1),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn failure_halts_and_resume_retries() {
+        let mut deps = mock_dependencies();
+        setup(deps.as_mut());
+
+        let msg = ExecuteMsg::ExecutePlan {
+            steps: vec![step("pot", 2), step("escrow", 3), step("voting", 4)],
+        };
+        let _res = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+        let _res = reply(deps.as_mut(), mock_env(), success(1)).unwrap();
+
+        // second step fails, third one is never sent
+        let res = reply(deps.as_mut(), mock_env(), failure(1, "bad migrate msg")).unwrap();
+        assert_eq!(res.messages.len(), 0);
+        let plan = load_plan(deps.as_ref(), 1);
+        assert_eq!(plan.status, PlanStatus::Halted);
+        assert_eq!(plan.current_step, 1);
+        assert_eq!(plan.steps[0].status, StepStatus::Succeeded);
+        assert_eq!(
+            plan.steps[1].status,
+            StepStatus::Failed {
+                error: "bad migrate msg".to_string()
+            }
+        );
+        assert_eq!(plan.steps[2].status, StepStatus::Pending);
+
+        // only halted plans can be resumed, and only by the admin
+        let msg = ExecuteMsg::ResumePlan { plan_id: 1 };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+
+        // resuming retries the failed step
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, migrate_msg("escrow", 3));
+        let plan = load_plan(deps.as_ref(), 1);
+        assert_eq!(plan.status, PlanStatus::InProgress);
+        assert_eq!(plan.steps[1].status, StepStatus::Pending);
+
+        let err = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap_err();
+        match err {
+            ContractError::PlanNotHalted { plan_id } => assert_eq!(plan_id, 1),
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let res = reply(deps.as_mut(), mock_env(), success(1)).unwrap();
+        assert_eq!(res.messages[0].msg, migrate_msg("voting", 4));
+        let _res = reply(deps.as_mut(), mock_env(), success(1)).unwrap();
+        let plan = load_plan(deps.as_ref(), 1);
+        assert_eq!(plan.status, PlanStatus::Completed);
+    }
+
+    #[test]
+    fn update_admin() {
+        let mut deps = mock_dependencies();
+        setup(deps.as_mut());
+
+        let msg = ExecuteMsg::UpdateAdmin {
+            admin: "new_gov".to_string(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => {}
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let _res = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+        let config = query_config(deps.as_ref()).unwrap();
+        assert_eq!(config.admin, Addr::unchecked("new_gov"));
+    }
+
+    #[test]
+    fn list_plans() {
+        let mut deps = mock_dependencies();
+        setup(deps.as_mut());
+
+        for code_id in 1..=3 {
+            let msg = ExecuteMsg::ExecutePlan {
+                steps: vec![step("pot", code_id)],
+            };
+            let _res = execute(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]), msg).unwrap();
+            let _res = reply(deps.as_mut(), mock_env(), success(code_id)).unwrap();
+        }
+
+        let msg = QueryMsg::ListPlans {
+            start_after: None,
+            limit: Some(2),
+        };
+        let res: ListPlansResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let ids: Vec<u64> = res.plans.iter().map(|plan| plan.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+
+        let msg = QueryMsg::ListPlans {
+            start_after: Some(2),
+            limit: None,
+        };
+        let res: ListPlansResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let ids: Vec<u64> = res.plans.iter().map(|plan| plan.id).collect();
+        assert_eq!(ids, vec![3]);
+    }
+
+    #[test]
+    fn raw_storage() {
+        let mut deps = mock_dependencies();
+        setup(deps.as_mut());
+
+        let msg = QueryMsg::RawStorage {
+            start_after: None,
+            limit: None,
+        };
+        let res: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        let keys: Vec<&[u8]> = res.entries.iter().map(|(k, _)| k.as_slice()).collect();
+        assert_eq!(keys, vec![&b"config"[..], &b"plan_seq"[..]]);
+    }
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("plan must have at least one step")]
+    EmptyPlan {},
+
+    #[error("plan {plan_id:?} is not halted")]
+    PlanNotHalted { plan_id: u64 },
+
+    #[error("plan {plan_id:?} is not in progress")]
+    PlanNotInProgress { plan_id: u64 },
+}
@@ -0,0 +1,8 @@
+pub mod contract;
+#[cfg(any(test, feature = "debug"))]
+pub mod debug;
+mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,62 @@
+use cosmwasm_std::Binary;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Config, Plan};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    /// admin defaults to the sender
+    pub admin: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Migrates the given contracts in order. A step is only dispatched once the
+    /// previous one succeeded, and the first failing step halts the plan.
+    /// This contract must be the admin of every contract in the plan.
+    ExecutePlan { steps: Vec<MigrateStep> },
+    /// Retries a halted plan, starting with the step that failed
+    ResumePlan { plan_id: u64 },
+    /// Hands the admin role over to another account
+    UpdateAdmin { admin: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateStep {
+    pub contract_addr: String,
+    pub new_code_id: u64,
+    pub msg: Binary,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    Config {},
+    /// Returns a plan with the status of each step
+    Plan {
+        plan_id: u64,
+    },
+    /// Lists plans in ascending ID order
+    ListPlans {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists raw storage entries in key order, for tests and local debugging.
+    /// Only compiled in test builds or with the "debug" feature.
+    #[cfg(any(test, feature = "debug"))]
+    RawStorage {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
+}
+
+// We define a custom struct for each query response
+pub type ConfigResponse = Config;
+pub type PlanResponse = Plan;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListPlansResponse {
+    pub plans: Vec<Plan>,
+}
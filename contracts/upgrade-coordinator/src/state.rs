@@ -0,0 +1,55 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use cosmwasm_std::{Addr, Binary};
+use cw_storage_plus::{Item, Map};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    /// admin is the only account allowed to run plans. Set it to the governance
+    /// module (or a voting contract) so every upgrade goes through a proposal.
+    pub admin: Addr,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    /// A step is dispatched and waiting for its reply
+    InProgress,
+    /// All steps migrated successfully
+    Completed,
+    /// A step failed, no later step was dispatched
+    Halted,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Pending,
+    Succeeded,
+    Failed { error: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Step {
+    pub contract_addr: Addr,
+    pub new_code_id: u64,
+    /// msg is passed as is to the migrate entry point of the contract
+    pub msg: Binary,
+    pub status: StepStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Plan {
+    pub id: u64,
+    pub status: PlanStatus,
+    /// current_step is the index of the dispatched step, or of the failed one once halted
+    pub current_step: u64,
+    pub steps: Vec<Step>,
+}
+
+/// PLAN_SEQ holds the last plan ID
+pub const PLAN_SEQ: Item<u64> = Item::new("plan_seq");
+pub const PLANS: Map<u64, Plan> = Map::new("plans");
@@ -443,6 +443,10 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             token_balance(deps, deps.api.addr_validate(address.as_str())?)
         }
         QueryMsg::Poll { poll_id } => query_poll(deps, poll_id),
+        #[cfg(any(test, feature = "debug"))]
+        QueryMsg::RawStorage { start_after, limit } => {
+            to_binary(&crate::debug::query_raw_storage(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -1,5 +1,7 @@
 pub mod coin_helpers;
 pub mod contract;
+#[cfg(any(test, feature = "debug"))]
+pub mod debug;
 mod error;
 pub mod msg;
 pub mod state;
@@ -1,4 +1,6 @@
 use crate::state::PollStatus;
+#[cfg(any(test, feature = "debug"))]
+use cosmwasm_std::Binary;
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -35,8 +37,19 @@ pub enum ExecuteMsg {
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     Config {},
-    TokenStake { address: String },
-    Poll { poll_id: u64 },
+    TokenStake {
+        address: String,
+    },
+    Poll {
+        poll_id: u64,
+    },
+    /// Lists raw storage entries in key order, for tests and local debugging.
+    /// Only compiled in test builds or with the "debug" feature.
+    #[cfg(any(test, feature = "debug"))]
+    RawStorage {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema)]
@@ -1,14 +1,16 @@
 #[cfg(test)]
 mod tests {
     use crate::contract::{execute, instantiate, query, VOTING_TOKEN};
+    use crate::debug::RawStorageResponse;
     use crate::error::ContractError;
     use crate::msg::{ExecuteMsg, InstantiateMsg, PollResponse, QueryMsg};
-    use crate::state::{config_read, PollStatus, State};
+    use crate::state::{config_read, PollStatus, State, TokenManager};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::{
-        attr, coins, from_binary, Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo,
-        Response, StdError, Timestamp, Uint128,
+        attr, coins, from_binary, from_slice, Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env,
+        MessageInfo, Response, StdError, Timestamp, Uint128,
     };
+    use cosmwasm_storage::to_length_prefixed;
 
     const DEFAULT_END_HEIGHT: u64 = 100800u64;
     const TEST_CREATOR: &str = "creator";
@@ -796,6 +798,43 @@ mod tests {
         assert_stake_tokens_result(11, None, execute_res, deps.as_mut());
     }
 
+    #[test]
+    fn raw_storage_lists_stakes_and_config() {
+        let mut deps = mock_dependencies(&[]);
+        mock_instantiate(deps.as_mut());
+
+        let msg = ExecuteMsg::StakeVotingTokens {};
+        let info = mock_info(TEST_VOTER, &coins(11, VOTING_TOKEN));
+        let _ = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+        // "bank" has a shorter length prefix than "config", so stakes come first
+        let msg = QueryMsg::RawStorage {
+            start_after: None,
+            limit: Some(1),
+        };
+        let page: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(1, page.entries.len());
+        let (key, value) = &page.entries[0];
+        let mut expected_key = to_length_prefixed(b"bank");
+        expected_key.extend_from_slice(TEST_VOTER.as_bytes());
+        assert_eq!(expected_key, key.as_slice());
+        let token_manager: TokenManager = from_slice(value).unwrap();
+        assert_eq!(Uint128::from(11u128), token_manager.token_balance);
+
+        let msg = QueryMsg::RawStorage {
+            start_after: Some(key.clone()),
+            limit: None,
+        };
+        let page: RawStorageResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(1, page.entries.len());
+        let (key, value) = &page.entries[0];
+        assert_eq!(to_length_prefixed(b"config"), key.as_slice());
+        let state: State = from_slice(value).unwrap();
+        assert_eq!(Uint128::from(11u128), state.staked_tokens);
+    }
+
     #[test]
     fn fails_insufficient_funds() {
         let mut deps = mock_dependencies(&[]);
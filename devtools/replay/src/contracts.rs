@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+use cosmwasm_std::{from_slice, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use serde::de::DeserializeOwned;
+
+/// Type-erased entry points of a contract, taking raw JSON messages
+pub trait Contract {
+    fn instantiate(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: &[u8],
+    ) -> Result<Response, String>;
+
+    fn execute(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: &[u8],
+    ) -> Result<Response, String>;
+
+    fn query(&self, deps: Deps, env: Env, msg: &[u8]) -> Result<Binary, String>;
+}
+
+struct Entrypoints<I, E, Q, IErr, EErr> {
+    instantiate: fn(DepsMut, Env, MessageInfo, I) -> Result<Response, IErr>,
+    execute: fn(DepsMut, Env, MessageInfo, E) -> Result<Response, EErr>,
+    query: fn(Deps, Env, Q) -> StdResult<Binary>,
+}
+
+impl<I, E, Q, IErr, EErr> Contract for Entrypoints<I, E, Q, IErr, EErr>
+where
+    I: DeserializeOwned,
+    E: DeserializeOwned,
+    Q: DeserializeOwned,
+    IErr: Display,
+    EErr: Display,
+{
+    fn instantiate(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: &[u8],
+    ) -> Result<Response, String> {
+        let msg = from_slice(msg).map_err(|e| e.to_string())?;
+        (self.instantiate)(deps, env, info, msg).map_err(|e| e.to_string())
+    }
+
+    fn execute(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: &[u8],
+    ) -> Result<Response, String> {
+        let msg = from_slice(msg).map_err(|e| e.to_string())?;
+        (self.execute)(deps, env, info, msg).map_err(|e| e.to_string())
+    }
+
+    fn query(&self, deps: Deps, env: Env, msg: &[u8]) -> Result<Binary, String> {
+        let msg = from_slice(msg).map_err(|e| e.to_string())?;
+        (self.query)(deps, env, msg).map_err(|e| e.to_string())
+    }
+}
+
+pub const CONTRACT_NAMES: &[&str] = &[
+    "cw-quadratic-funding",
+    "cw20-pot",
+    "escrow",
+    "nameservice",
+    "simple-option",
+];
+
+/// Looks up a contract by its directory name under `contracts/`
+pub fn by_name(name: &str) -> Option<Box<dyn Contract>> {
+    let contract: Box<dyn Contract> = match name {
+        "cw-quadratic-funding" => Box::new(Entrypoints {
+            instantiate: cw_quadratic_funding::contract::init,
+            execute: cw_quadratic_funding::contract::execute,
+            query: cw_quadratic_funding::contract::query,
+        }),
+        "cw20-pot" => Box::new(Entrypoints {
+            instantiate: cw20_pot::contract::instantiate,
+            execute: cw20_pot::contract::execute,
+            query: cw20_pot::contract::query,
+        }),
+        "escrow" => Box::new(Entrypoints {
+            instantiate: cw_escrow::contract::instantiate,
+            execute: cw_escrow::contract::execute,
+            query: cw_escrow::contract::query,
+        }),
+        "nameservice" => Box::new(Entrypoints {
+            instantiate: cw_nameservice::contract::instantiate,
+            execute: cw_nameservice::contract::execute,
+            query: cw_nameservice::contract::query,
+        }),
+        "simple-option" => Box::new(Entrypoints {
+            instantiate: simple_option::contract::instantiate,
+            execute: simple_option::contract::execute,
+            query: simple_option::contract::query,
+        }),
+        _ => return None,
+    };
+    Some(contract)
+}
@@ -0,0 +1,207 @@
+//! Replays a recorded sequence of instantiate/execute/query messages through
+//! the native code of one of the contracts in this repo, using a mock chain,
+//! and prints the outcome and storage changes of every step.
+//!
+//! Usage: `cargo run -- <contract> <recording.json>`
+
+mod contracts;
+mod storage;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::process;
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    coin, BankMsg, Coin, CosmosMsg, Env, MessageInfo, Response, Timestamp, Uint128,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::contracts::{Contract, CONTRACT_NAMES};
+
+/// Seconds the mock chain advances per step, unless the step sets its own time
+const BLOCK_TIME: u64 = 5;
+
+#[derive(Deserialize)]
+struct Recording {
+    steps: Vec<Step>,
+}
+
+#[derive(Deserialize)]
+struct Step {
+    /// Block height the message was included at, defaults to the previous height + 1
+    height: Option<u64>,
+    /// Block time in seconds, defaults to the previous time + BLOCK_TIME
+    time: Option<u64>,
+    #[serde(flatten)]
+    action: Action,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Action {
+    Instantiate {
+        sender: String,
+        #[serde(default)]
+        funds: Vec<Coin>,
+        msg: Value,
+    },
+    Execute {
+        sender: String,
+        #[serde(default)]
+        funds: Vec<Coin>,
+        msg: Value,
+    },
+    Query {
+        msg: Value,
+    },
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("Usage: {} <contract> <recording.json>", args[0]);
+        eprintln!("Contracts: {}", CONTRACT_NAMES.join(", "));
+        process::exit(1);
+    }
+
+    let contract = contracts::by_name(&args[1]).unwrap_or_else(|| {
+        eprintln!("Unknown contract {}", args[1]);
+        eprintln!("Contracts: {}", CONTRACT_NAMES.join(", "));
+        process::exit(1);
+    });
+    let recording: Recording = fs::read(&args[2])
+        .map_err(|e| e.to_string())
+        .and_then(|raw| serde_json::from_slice(&raw).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            eprintln!("Cannot read {}: {}", args[2], e);
+            process::exit(1);
+        });
+
+    let failed = replay(contract.as_ref(), recording.steps);
+    if failed > 0 {
+        println!("{} step(s) failed", failed);
+    }
+}
+
+/// Runs all steps in order and returns the number of failed ones.
+/// Like on chain, a failed instantiate/execute leaves no storage changes behind.
+fn replay(contract: &dyn Contract, steps: Vec<Step>) -> usize {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let mut balances: BTreeMap<String, Uint128> = BTreeMap::new();
+    let mut failed = 0;
+
+    for (i, step) in steps.into_iter().enumerate() {
+        advance_block(&mut env, i == 0, step.height, step.time);
+        let before = storage::dump(&deps.storage);
+        let balances_before = balances.clone();
+
+        let (label, res) = match step.action {
+            Action::Query { msg } => {
+                println!("#{} query at height {}", i, env.block.height);
+                match contract.query(deps.as_ref(), env.clone(), &to_vec(&msg)) {
+                    Ok(bin) => println!("  ok: {}", String::from_utf8_lossy(bin.as_slice())),
+                    Err(err) => {
+                        failed += 1;
+                        println!("  error: {}", err);
+                    }
+                }
+                continue;
+            }
+            Action::Instantiate { sender, funds, msg } => {
+                let info = mock_info(&sender, &funds);
+                receive_funds(&mut balances, &info);
+                deps.querier
+                    .update_balance(MOCK_CONTRACT_ADDR, to_coins(&balances));
+                let res = contract.instantiate(deps.as_mut(), env.clone(), info, &to_vec(&msg));
+                (format!("instantiate by {}", sender), res)
+            }
+            Action::Execute { sender, funds, msg } => {
+                let info = mock_info(&sender, &funds);
+                receive_funds(&mut balances, &info);
+                deps.querier
+                    .update_balance(MOCK_CONTRACT_ADDR, to_coins(&balances));
+                let res = contract.execute(deps.as_mut(), env.clone(), info, &to_vec(&msg));
+                (format!("execute by {}", sender), res)
+            }
+        };
+
+        println!("#{} {} at height {}", i, label, env.block.height);
+        match res {
+            Ok(res) => {
+                print_response(&res);
+                send_funds(&mut balances, &res);
+                for line in storage::diff(&before, &storage::dump(&deps.storage)) {
+                    println!("  {}", line);
+                }
+            }
+            Err(err) => {
+                failed += 1;
+                println!("  error: {}", err);
+                storage::restore(&mut deps.storage, &before);
+                // the attached funds were never transferred
+                balances = balances_before;
+            }
+        }
+        deps.querier
+            .update_balance(MOCK_CONTRACT_ADDR, to_coins(&balances));
+    }
+    failed
+}
+
+fn advance_block(env: &mut Env, first: bool, height: Option<u64>, time: Option<u64>) {
+    env.block.height = match height {
+        Some(height) => height,
+        None if first => env.block.height,
+        None => env.block.height + 1,
+    };
+    env.block.time = match time {
+        Some(time) => Timestamp::from_seconds(time),
+        None if first => env.block.time,
+        None => env.block.time.plus_seconds(BLOCK_TIME),
+    };
+}
+
+fn print_response(res: &Response) {
+    for attr in &res.attributes {
+        println!("  attr {} = {}", attr.key, attr.value);
+    }
+    for sub in &res.messages {
+        println!("  msg {:?}", sub.msg);
+    }
+    if let Some(data) = &res.data {
+        println!("  data {}", data);
+    }
+}
+
+fn receive_funds(balances: &mut BTreeMap<String, Uint128>, info: &MessageInfo) {
+    for c in &info.funds {
+        *balances.entry(c.denom.clone()).or_default() += c.amount;
+    }
+}
+
+/// Only bank sends affect the contract balance, other messages are just printed
+fn send_funds(balances: &mut BTreeMap<String, Uint128>, res: &Response) {
+    for sub in &res.messages {
+        if let CosmosMsg::Bank(BankMsg::Send { amount, .. }) = &sub.msg {
+            for c in amount {
+                let balance = balances.entry(c.denom.clone()).or_default();
+                *balance = balance.saturating_sub(c.amount);
+            }
+        }
+    }
+}
+
+fn to_coins(balances: &BTreeMap<String, Uint128>) -> Vec<Coin> {
+    balances
+        .iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| coin(amount.u128(), denom))
+        .collect()
+}
+
+fn to_vec(msg: &Value) -> Vec<u8> {
+    serde_json::to_vec(msg).expect("JSON values always serialize")
+}
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Order, Storage};
+
+pub type Dump = BTreeMap<Vec<u8>, Vec<u8>>;
+
+pub fn dump(storage: &dyn Storage) -> Dump {
+    storage.range(None, None, Order::Ascending).collect()
+}
+
+/// Puts the storage back into the state captured by `before`
+pub fn restore(storage: &mut dyn Storage, before: &Dump) {
+    let after = dump(storage);
+    for key in after.keys() {
+        if !before.contains_key(key) {
+            storage.remove(key);
+        }
+    }
+    for (key, value) in before {
+        if after.get(key) != Some(value) {
+            storage.set(key, value);
+        }
+    }
+}
+
+/// Renders the differences between two dumps, one line per touched key
+pub fn diff(before: &Dump, after: &Dump) -> Vec<String> {
+    let mut lines = vec![];
+    for (key, old) in before {
+        match after.get(key) {
+            None => lines.push(format!("- {}", printable(key))),
+            Some(new) if new != old => lines.push(format!(
+                "~ {}: {} -> {}",
+                printable(key),
+                printable(old),
+                printable(new)
+            )),
+            Some(_) => {}
+        }
+    }
+    for (key, new) in after {
+        if !before.contains_key(key) {
+            lines.push(format!("+ {} = {}", printable(key), printable(new)));
+        }
+    }
+    lines
+}
+
+/// Shows printable ASCII as is and escapes everything else, so that both
+/// length-prefixed keys and JSON values stay readable
+fn printable(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len());
+    for &b in bytes {
+        if (0x20..0x7f).contains(&b) && b != b'\\' {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("\\x{:02x}", b));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    #[test]
+    fn diff_and_restore() {
+        let mut storage = MockStorage::new();
+        storage.set(b"kept", b"1");
+        storage.set(b"changed", b"\"old\"");
+        storage.set(b"removed", b"x");
+        let before = dump(&storage);
+
+        storage.set(b"changed", b"\"new\"");
+        storage.remove(b"removed");
+        storage.set(b"\x00\x03new", b"y");
+        let after = dump(&storage);
+
+        assert_eq!(
+            diff(&before, &after),
+            vec![
+                "~ changed: \"old\" -> \"new\"".to_string(),
+                "- removed".to_string(),
+                "+ \\x00\\x03new = y".to_string(),
+            ]
+        );
+
+        restore(&mut storage, &before);
+        assert_eq!(dump(&storage), before);
+    }
+}